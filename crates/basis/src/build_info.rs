@@ -0,0 +1,131 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use git2::Repository;
+
+use crate::git::get_head_commit::GetHeadCommit;
+use crate::time::to_time::ToCommitTime;
+
+pub struct BuildInfo {
+    pub commit_id: String,
+    pub build_date: String,
+}
+
+/// Resolves build provenance for stamping into a binary from `build.rs`:
+/// the short commit id and commit date when built from a git checkout,
+/// falling back to a `release.txt` next to the manifest for tarball
+/// builds, and finally to `UNKNOWN` with today's date.
+pub fn get_info() -> BuildInfo {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    from_repository(&manifest_dir)
+        .or_else(|| from_release_file(&manifest_dir))
+        .unwrap_or_else(|| BuildInfo {
+            commit_id: "UNKNOWN".to_string(),
+            build_date: Utc::now().format("%Y-%m-%d").to_string(),
+        })
+}
+
+/// Emits the `cargo:rustc-env` directives `build.rs` needs to make
+/// `COMMIT_ID` and `BUILD_DATE` available via `env!` at compile time.
+pub fn emit() {
+    let info = get_info();
+    println!("cargo:rustc-env=COMMIT_ID={}", info.commit_id);
+    println!("cargo:rustc-env=BUILD_DATE={}", info.build_date);
+}
+
+fn open_repository(manifest_dir: &Path) -> Option<Repository> {
+    if let Ok(repo) = Repository::open(manifest_dir) {
+        return Some(repo);
+    }
+    Repository::open(manifest_dir.parent()?).ok()
+}
+
+fn from_repository(manifest_dir: &Path) -> Option<BuildInfo> {
+    let repo = open_repository(manifest_dir)?;
+    let commit = repo.get_head_commit().ok()?;
+    let commit_id = commit.id().to_string().chars().take(8).collect();
+    let build_date = commit.to_commit_time().format("%Y-%m-%d").to_string();
+    Some(BuildInfo {
+        commit_id,
+        build_date,
+    })
+}
+
+fn from_release_file(manifest_dir: &Path) -> Option<BuildInfo> {
+    let contents = fs::read_to_string(manifest_dir.join("release.txt")).ok()?;
+    let mut lines = contents.lines();
+    let commit_id = lines.next()?.trim().to_string();
+    let build_date = lines
+        .next()
+        .map(|line| line.trim().to_string())
+        .unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string());
+
+    Some(BuildInfo {
+        commit_id,
+        build_date,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            static COUNTER: AtomicU32 = AtomicU32::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let dir = env::temp_dir().join(format!(
+                "basis_build_info_test_{}_{id}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn release_file_present_with_both_lines() {
+        let dir = TempDir::new();
+        fs::write(dir.0.join("release.txt"), "abcdef12\n2024-01-01\n").unwrap();
+
+        let info = from_release_file(&dir.0).unwrap();
+        assert_eq!(info.commit_id, "abcdef12");
+        assert_eq!(info.build_date, "2024-01-01");
+    }
+
+    #[test]
+    fn release_file_missing_second_line_defaults_to_today() {
+        let dir = TempDir::new();
+        fs::write(dir.0.join("release.txt"), "abcdef12\n").unwrap();
+
+        let info = from_release_file(&dir.0).unwrap();
+        assert_eq!(info.commit_id, "abcdef12");
+        assert_eq!(info.build_date, Utc::now().format("%Y-%m-%d").to_string());
+    }
+
+    #[test]
+    fn release_file_missing_returns_none() {
+        let dir = TempDir::new();
+        assert!(from_release_file(&dir.0).is_none());
+    }
+
+    #[test]
+    fn no_repository_and_no_release_file_falls_through() {
+        let dir = TempDir::new();
+        assert!(from_repository(&dir.0).is_none());
+        assert!(from_release_file(&dir.0).is_none());
+    }
+}
@@ -0,0 +1,177 @@
+use git2::{BranchType, Commit, Error, Oid, Repository, Sort};
+
+pub trait Commits {
+    /// Lists up to `limit` commits reachable from `start`, newest first.
+    ///
+    /// `start` is resolved flexibly: first as a raw `Oid`, then as a
+    /// local branch name, then as a tag name. `None` starts from `HEAD`.
+    fn commits(&self, start: Option<&str>, limit: usize) -> Result<Vec<Commit<'_>>, Error>;
+}
+
+impl Commits for Repository {
+    fn commits(&self, start: Option<&str>, limit: usize) -> Result<Vec<Commit<'_>>, Error> {
+        let mut revwalk = self.revwalk()?;
+        revwalk.set_sorting(Sort::TIME)?;
+
+        match start {
+            Some(reference) => revwalk.push(self.resolve_start(reference)?)?,
+            None => revwalk.push_head()?,
+        }
+
+        revwalk
+            .take(limit)
+            .map(|oid| self.find_commit(oid?))
+            .collect()
+    }
+}
+
+trait ResolveStart {
+    fn resolve_start(&self, reference: &str) -> Result<Oid, Error>;
+}
+
+impl ResolveStart for Repository {
+    fn resolve_start(&self, reference: &str) -> Result<Oid, Error> {
+        let oid = if let Ok(oid) = Oid::from_str(reference) {
+            oid
+        } else if let Some(target) = self
+            .find_branch(reference, BranchType::Local)
+            .ok()
+            .and_then(|branch| branch.get().target())
+        {
+            target
+        } else {
+            let mut tag_oid = None;
+            self.tag_foreach(|oid, name| {
+                if name == format!("refs/tags/{reference}").as_bytes() {
+                    tag_oid = Some(oid);
+                    false
+                } else {
+                    true
+                }
+            })?;
+            tag_oid.ok_or_else(|| Error::from_str(&format!("Couldn't resolve '{reference}'")))?
+        };
+
+        // Peel annotated tags (and any other tag-ish object) down to the
+        // commit `revwalk` expects to start from.
+        self.find_object(oid, None)?.peel_to_commit().map(|c| c.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    struct TempRepo {
+        dir: PathBuf,
+        repo: Repository,
+    }
+
+    impl TempRepo {
+        fn new() -> Self {
+            static COUNTER: AtomicU32 = AtomicU32::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let dir = std::env::temp_dir().join(format!(
+                "basis_commits_test_{}_{id}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let repo = Repository::init(&dir).unwrap();
+            TempRepo { dir, repo }
+        }
+
+        fn commit(&self, message: &str) -> Oid {
+            let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+            let tree_id = self.repo.index().unwrap().write_tree().unwrap();
+            let tree = self.repo.find_tree(tree_id).unwrap();
+            let parent = self
+                .repo
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<&Commit> = parent.iter().collect();
+
+            self.repo
+                .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+                .unwrap()
+        }
+    }
+
+    impl Drop for TempRepo {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn resolves_by_full_oid() {
+        let temp_repo = TempRepo::new();
+        let oid = temp_repo.commit("first");
+
+        let commits = temp_repo.repo.commits(Some(&oid.to_string()), 10).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), oid);
+    }
+
+    #[test]
+    fn resolves_by_branch_name() {
+        let temp_repo = TempRepo::new();
+        let oid = temp_repo.commit("first");
+        let commit = temp_repo.repo.find_commit(oid).unwrap();
+        temp_repo.repo.branch("feature", &commit, false).unwrap();
+
+        let commits = temp_repo.repo.commits(Some("feature"), 10).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), oid);
+    }
+
+    #[test]
+    fn resolves_by_lightweight_tag() {
+        let temp_repo = TempRepo::new();
+        let oid = temp_repo.commit("first");
+        let object = temp_repo.repo.find_object(oid, None).unwrap();
+        temp_repo.repo.tag_lightweight("v1", &object, false).unwrap();
+
+        let commits = temp_repo.repo.commits(Some("v1"), 10).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), oid);
+    }
+
+    #[test]
+    fn resolves_by_annotated_tag() {
+        let temp_repo = TempRepo::new();
+        let oid = temp_repo.commit("first");
+        let object = temp_repo.repo.find_object(oid, None).unwrap();
+        let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+        temp_repo
+            .repo
+            .tag("v2", &object, &signature, "release", false)
+            .unwrap();
+
+        let commits = temp_repo.repo.commits(Some("v2"), 10).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].id(), oid);
+    }
+
+    #[test]
+    fn unresolvable_reference_is_an_error() {
+        let temp_repo = TempRepo::new();
+        temp_repo.commit("first");
+
+        assert!(temp_repo.repo.commits(Some("does-not-exist"), 10).is_err());
+    }
+
+    #[test]
+    fn limit_truncates_history() {
+        let temp_repo = TempRepo::new();
+        temp_repo.commit("first");
+        temp_repo.commit("second");
+        temp_repo.commit("third");
+
+        let commits = temp_repo.repo.commits(None, 2).unwrap();
+        assert_eq!(commits.len(), 2);
+    }
+}
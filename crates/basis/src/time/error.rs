@@ -0,0 +1,20 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidTimestamp(i64),
+    InvalidOffset(i32),
+    InvalidDate(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidTimestamp(seconds) => write!(f, "invalid timestamp: {seconds}"),
+            Error::InvalidOffset(minutes) => write!(f, "invalid utc offset: {minutes} minutes"),
+            Error::InvalidDate(input) => write!(f, "unrecognized date format: {input}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
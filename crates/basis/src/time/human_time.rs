@@ -0,0 +1,120 @@
+use chrono::{DateTime, FixedOffset};
+
+use super::to_time::ToTime;
+
+/// Renders a timestamp relative to `now` the way blame/log UIs do,
+/// e.g. `"3 hours ago"`, `"yesterday"`, `"in 3 days"`.
+pub trait ToHumanTime {
+    fn to_human_time(&self, now: DateTime<FixedOffset>) -> String;
+}
+
+impl ToHumanTime for DateTime<FixedOffset> {
+    fn to_human_time(&self, now: DateTime<FixedOffset>) -> String {
+        let duration = now.signed_duration_since(*self);
+        let future = duration.num_milliseconds() < 0;
+        let duration = if future { -duration } else { duration };
+
+        let phrase = if duration.num_seconds() < 5 {
+            "just now".to_string()
+        } else if duration.num_minutes() < 1 {
+            pluralize(duration.num_seconds(), "second")
+        } else if duration.num_hours() < 1 {
+            pluralize(duration.num_minutes(), "minute")
+        } else if duration.num_days() < 1 {
+            pluralize(duration.num_hours(), "hour")
+        } else if duration.num_days() < 2 && !future {
+            return "yesterday".to_string();
+        } else if duration.num_days() < 30 {
+            pluralize(duration.num_days(), "day")
+        } else {
+            let months = duration.num_days() / 30;
+            if months < 12 {
+                pluralize(months, "month")
+            } else {
+                pluralize(months / 12, "year")
+            }
+        };
+
+        if duration.num_seconds() < 5 {
+            return phrase;
+        }
+        if future {
+            format!("in {phrase}")
+        } else {
+            format!("{phrase} ago")
+        }
+    }
+}
+
+impl ToHumanTime for i64 {
+    fn to_human_time(&self, now: DateTime<FixedOffset>) -> String {
+        self.to_time().to_human_time(now)
+    }
+}
+
+fn pluralize(count: i64, unit: &str) -> String {
+    if count == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{count} {unit}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2022-08-18T10:44:15+02:00").unwrap()
+    }
+
+    fn ago(duration: Duration) -> String {
+        (now() - duration).to_human_time(now())
+    }
+
+    fn from_now(duration: Duration) -> String {
+        (now() + duration).to_human_time(now())
+    }
+
+    #[test]
+    fn just_now_boundary() {
+        assert_eq!(ago(Duration::seconds(4)), "just now");
+        assert_eq!(ago(Duration::seconds(5)), "5 seconds ago");
+    }
+
+    #[test]
+    fn minutes_boundary() {
+        assert_eq!(ago(Duration::seconds(59)), "59 seconds ago");
+        assert_eq!(ago(Duration::seconds(60)), "1 minute ago");
+    }
+
+    #[test]
+    fn hours_boundary() {
+        assert_eq!(ago(Duration::minutes(59)), "59 minutes ago");
+        assert_eq!(ago(Duration::hours(1)), "1 hour ago");
+    }
+
+    #[test]
+    fn yesterday_window() {
+        assert_eq!(ago(Duration::hours(23) + Duration::minutes(59)), "23 hours ago");
+        assert_eq!(ago(Duration::days(1)), "yesterday");
+        assert_eq!(ago(Duration::days(1) + Duration::hours(23)), "yesterday");
+        assert_eq!(ago(Duration::days(2)), "2 days ago");
+    }
+
+    #[test]
+    fn months_and_years_boundary() {
+        assert_eq!(ago(Duration::days(29)), "29 days ago");
+        assert_eq!(ago(Duration::days(30)), "1 month ago");
+        assert_eq!(ago(Duration::days(364)), "1 year ago");
+        assert_eq!(ago(Duration::days(359)), "11 months ago");
+    }
+
+    #[test]
+    fn future_phrasing() {
+        assert_eq!(from_now(Duration::hours(2)), "in 2 hours");
+        assert_eq!(from_now(Duration::days(3)), "in 3 days");
+    }
+}
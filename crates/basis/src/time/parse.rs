@@ -0,0 +1,184 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone};
+
+use super::error::Error;
+
+/// Parses the textual date forms `git` itself emits: a bare unix
+/// timestamp (optionally followed by a zone, e.g. `1660874655 +0200`),
+/// RFC2822, RFC3339/ISO-8601, the short `%Y-%m-%d` form, and git's
+/// relative spellings (`"2 weeks ago"`, `"yesterday"`, `"now"`).
+///
+/// Relative forms are resolved against `now` rather than the real
+/// current time, so callers can anchor parsing for reproducible tests.
+pub fn parse(input: &str, now: DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>, Error> {
+    let input = input.trim();
+
+    if let Some(date_time) = parse_unix(input) {
+        return Ok(date_time);
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc2822(input) {
+        return Ok(date_time);
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(date_time);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        let date_time = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(|| Error::InvalidDate(input.to_string()))?;
+        return now
+            .timezone()
+            .from_local_datetime(&date_time)
+            .single()
+            .ok_or_else(|| Error::InvalidDate(input.to_string()));
+    }
+    if let Some(duration) = parse_relative(input) {
+        return Ok(now - duration);
+    }
+
+    Err(Error::InvalidDate(input.to_string()))
+}
+
+fn parse_unix(input: &str) -> Option<DateTime<FixedOffset>> {
+    let mut parts = input.split_whitespace();
+    let seconds: i64 = parts.next()?.parse().ok()?;
+    let utc_time = DateTime::from_timestamp(seconds, 0)?;
+
+    match parts.next() {
+        Some(zone) if parts.next().is_none() => {
+            let offset = FixedOffset::east_opt(parse_zone_offset(zone)?)?;
+            Some(utc_time.with_timezone(&offset))
+        }
+        None => Some(utc_time.fixed_offset()),
+        _ => None,
+    }
+}
+
+fn parse_zone_offset(zone: &str) -> Option<i32> {
+    let (sign, digits) = match zone.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, zone.strip_prefix('+').unwrap_or(zone)),
+    };
+    if digits.len() != 4 {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let minutes: i32 = digits[2..4].parse().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn parse_relative(input: &str) -> Option<Duration> {
+    match input {
+        "now" => return Some(Duration::zero()),
+        "yesterday" => return Some(Duration::days(1)),
+        _ => {}
+    }
+
+    let input = input.strip_suffix(" ago")?;
+    let mut parts = input.split_whitespace();
+    let amount: i64 = parts.next()?.parse().ok()?;
+    let unit = parts.next()?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let duration = match unit.trim_end_matches('s') {
+        "second" => Duration::seconds(amount),
+        "minute" => Duration::minutes(amount),
+        "hour" => Duration::hours(amount),
+        "day" => Duration::days(amount),
+        "week" => Duration::weeks(amount),
+        "month" => Duration::days(amount * 30),
+        "year" => Duration::days(amount * 365),
+        _ => return None,
+    };
+    Some(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2022-08-18T10:44:15+02:00").unwrap()
+    }
+
+    #[test]
+    fn parses_bare_unix_timestamp() {
+        let parsed = parse("1660874655", now()).unwrap();
+        assert_eq!(parsed.timestamp(), 1660874655);
+        assert_eq!(parsed.offset().local_minus_utc(), 0);
+    }
+
+    #[test]
+    fn parses_unix_timestamp_with_zone() {
+        let parsed = parse("1660874655 +0200", now()).unwrap();
+        assert_eq!(parsed.timestamp(), 1660874655);
+        assert_eq!(parsed.offset().local_minus_utc(), 2 * 3600);
+    }
+
+    #[test]
+    fn parses_unix_timestamp_with_negative_zone() {
+        let parsed = parse("1660874655 -0530", now()).unwrap();
+        assert_eq!(parsed.offset().local_minus_utc(), -(5 * 3600 + 30 * 60));
+    }
+
+    #[test]
+    fn rejects_unix_timestamp_with_malformed_zone() {
+        assert!(parse("1660874655 +2", now()).is_err());
+    }
+
+    #[test]
+    fn parses_rfc2822() {
+        let parsed = parse("Thu, 18 Aug 2022 10:44:15 +0200", now()).unwrap();
+        assert_eq!(parsed.timestamp(), 1660812255);
+    }
+
+    #[test]
+    fn parses_rfc3339() {
+        let parsed = parse("2022-08-18T10:44:15+02:00", now()).unwrap();
+        assert_eq!(parsed, now());
+    }
+
+    #[test]
+    fn parses_short_date() {
+        let parsed = parse("2022-08-18", now()).unwrap();
+        assert_eq!(parsed.date_naive(), now().date_naive());
+        assert_eq!(parsed.format("%H:%M:%S").to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn parses_now() {
+        assert_eq!(parse("now", now()).unwrap(), now());
+    }
+
+    #[test]
+    fn parses_yesterday() {
+        assert_eq!(parse("yesterday", now()).unwrap(), now() - Duration::days(1));
+    }
+
+    #[test]
+    fn parses_relative_weeks_ago() {
+        assert_eq!(
+            parse("2 weeks ago", now()).unwrap(),
+            now() - Duration::weeks(2)
+        );
+    }
+
+    #[test]
+    fn parses_relative_singular_unit() {
+        assert_eq!(
+            parse("1 day ago", now()).unwrap(),
+            now() - Duration::days(1)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_relative_unit() {
+        assert!(parse("2 fortnights ago", now()).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(parse("not a date", now()).is_err());
+    }
+}
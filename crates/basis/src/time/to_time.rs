@@ -1,13 +1,64 @@
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, Utc};
+use git2::Commit;
+
+use super::error::Error;
 
 pub trait ToTime {
+    /// Converts bare epoch seconds to UTC, since an `i64` alone carries
+    /// no timezone information. Callers who have the commit's own UTC
+    /// offset should use [`ToCommitTime`] instead to preserve the
+    /// author's wall-clock time. Falls back to the current UTC time if
+    /// the timestamp is out of range.
     fn to_time(&self) -> DateTime<FixedOffset>;
+
+    fn try_to_time(&self) -> Result<DateTime<FixedOffset>, Error>;
 }
 
 impl ToTime for i64 {
     fn to_time(&self) -> DateTime<FixedOffset> {
-        let china_timezone = FixedOffset::east_opt(8 * 3600).unwrap();
-        let utc_time = DateTime::from_timestamp(*self, 0).unwrap();
-        utc_time.with_timezone(&china_timezone)
+        self.try_to_time()
+            .unwrap_or_else(|_| Utc::now().fixed_offset())
+    }
+
+    fn try_to_time(&self) -> Result<DateTime<FixedOffset>, Error> {
+        let utc_time = DateTime::from_timestamp(*self, 0).ok_or(Error::InvalidTimestamp(*self))?;
+        Ok(utc_time.fixed_offset())
+    }
+}
+
+/// Converts commit epoch seconds to the author's own wall-clock time,
+/// using the UTC offset (in minutes) git stores alongside the timestamp.
+/// Falls back to the current UTC time on conversion failure.
+pub fn to_commit_time(seconds: i64, offset_minutes: i32) -> DateTime<FixedOffset> {
+    try_to_commit_time(seconds, offset_minutes).unwrap_or_else(|_| Utc::now().fixed_offset())
+}
+
+pub fn try_to_commit_time(
+    seconds: i64,
+    offset_minutes: i32,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let offset =
+        FixedOffset::east_opt(offset_minutes * 60).ok_or(Error::InvalidOffset(offset_minutes))?;
+    let utc_time = DateTime::from_timestamp(seconds, 0).ok_or(Error::InvalidTimestamp(seconds))?;
+    Ok(utc_time.with_timezone(&offset))
+}
+
+pub trait ToCommitTime {
+    /// Converts to the author's wall-clock time, falling back to the
+    /// current UTC time if the commit's timestamp or offset is invalid.
+    fn to_commit_time(&self) -> DateTime<FixedOffset>;
+
+    fn try_to_commit_time(&self) -> Result<DateTime<FixedOffset>, Error>;
+}
+
+impl ToCommitTime for Commit<'_> {
+    fn to_commit_time(&self) -> DateTime<FixedOffset> {
+        let time = self.time();
+        to_commit_time(time.seconds(), time.offset_minutes())
+    }
+
+    fn try_to_commit_time(&self) -> Result<DateTime<FixedOffset>, Error> {
+        let time = self.time();
+        try_to_commit_time(time.seconds(), time.offset_minutes())
     }
 }